@@ -10,17 +10,72 @@ pub struct AudioDevice {
 /// List all available input audio devices
 pub fn list_devices() -> Result<Vec<AudioDevice>, String> {
     let host = cpal::default_host();
-    let devices = host.input_devices()
+    let devices = host
+        .input_devices()
         .map_err(|e| format!("Failed to get input devices: {}", e))?;
 
     let result: Vec<AudioDevice> = devices
         .filter_map(|d| {
             d.name().ok().map(|name| AudioDevice {
+                id: stable_device_id(&host, &name),
                 name,
-                id: uuid::Uuid::new_v4().to_string(),
             })
         })
         .collect();
 
     Ok(result)
 }
+
+/// Resolve a saved `AudioDevice.id` back to its underlying `cpal::Device`.
+/// Falls back to the host's default input device when the saved one can't
+/// be found (e.g. it was unplugged since the last launch).
+pub fn get_device_by_id(id: &str) -> Result<cpal::Device, String> {
+    let host = cpal::default_host();
+    let matched = host
+        .input_devices()
+        .map_err(|e| format!("Failed to get input devices: {}", e))?
+        .find(|d| {
+            d.name()
+                .map(|name| stable_device_id(&host, &name) == id)
+                .unwrap_or(false)
+        });
+
+    matched
+        .or_else(|| host.default_input_device())
+        .ok_or_else(|| "no matching or default input device available".to_string())
+}
+
+/// A stable identifier derived from the device's name and its host, so the
+/// same physical device resolves to the same id across app launches
+/// instead of a fresh `Uuid::new_v4()` every time devices are listed.
+fn stable_device_id(host: &cpal::Host, name: &str) -> String {
+    format!("{:?}:{}", host.id(), name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn same_device_name_produces_the_same_id_across_calls() {
+        let host = cpal::default_host();
+        let first = stable_device_id(&host, "Built-in Microphone");
+        let second = stable_device_id(&host, "Built-in Microphone");
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn different_device_names_produce_different_ids() {
+        let host = cpal::default_host();
+        let mic = stable_device_id(&host, "Built-in Microphone");
+        let line_in = stable_device_id(&host, "Line In");
+        assert_ne!(mic, line_in);
+    }
+
+    #[test]
+    fn stable_id_embeds_the_device_name() {
+        let host = cpal::default_host();
+        let id = stable_device_id(&host, "USB Headset");
+        assert!(id.ends_with("USB Headset"));
+    }
+}
@@ -3,6 +3,8 @@
 
 pub mod recorder;
 pub mod device;
+pub mod vad;
 
 pub use recorder::Recorder;
 pub use device::{list_devices, AudioDevice};
+pub use vad::VoiceActivityDetector;
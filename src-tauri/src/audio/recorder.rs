@@ -1,17 +1,120 @@
-use cpal::{traits::StreamTrait, Stream};
+use cpal::traits::{DeviceTrait, HostTrait, StreamTrait};
+use cpal::Stream;
 use std::sync::{Arc, Mutex};
+use tauri::{AppHandle, Emitter};
 
-/// Audio recorder that captures microphone input
+use crate::audio::{device, VoiceActivityDetector};
+use crate::models::AppSettings;
+
+/// Tauri event emitted with the current peak amplitude (0.0-1.0) while a
+/// stream is capturing, so the frontend can drive a live VU meter.
+const AUDIO_LEVEL_EVENT: &str = "audio-level";
+
+/// Tauri event emitted once auto-stop detects trailing silence and the
+/// stream has been torn down.
+const RECORDING_COMPLETE_EVENT: &str = "recording-complete";
+
+/// Audio recorder that captures microphone input.
+///
+/// While a stream is running, the capture callback both appends samples to
+/// an internal buffer (later handed to the transcription engine) and pushes
+/// a peak-amplitude level out to the frontend via `audio-level` events.
 pub struct Recorder {
     stream: Arc<Mutex<Option<Stream>>>,
+    samples: Arc<Mutex<Vec<f32>>>,
+    level: Arc<Mutex<f32>>,
 }
 
 impl Recorder {
     pub fn new() -> Self {
         Self {
             stream: Arc::new(Mutex::new(None)),
+            samples: Arc::new(Mutex::new(Vec::new())),
+            level: Arc::new(Mutex::new(0.0)),
         }
     }
+
+    /// Current peak amplitude of the most recently captured buffer.
+    pub fn level(&self) -> f32 {
+        *self.level.lock().unwrap()
+    }
+
+    /// Build an input stream on the device saved in
+    /// `settings.input_device_id` and start capturing. Falls back to the
+    /// host's default input device when the saved one can't be resolved
+    /// (e.g. it was unplugged since the last launch).
+    ///
+    /// When `settings.auto_stop_enabled` is set, captured audio is also fed
+    /// through a [`VoiceActivityDetector`]; once it observes speech followed
+    /// by `settings.silence_timeout_ms` of silence, the stream is torn down
+    /// automatically and a `recording-complete` event is emitted.
+    pub fn start(&self, settings: &AppSettings, app: AppHandle) -> Result<(), String> {
+        let cpal_device = match &settings.input_device_id {
+            Some(id) => device::get_device_by_id(id)?,
+            None => cpal::default_host()
+                .default_input_device()
+                .ok_or_else(|| "no default input device available".to_string())?,
+        };
+        let config = cpal_device
+            .default_input_config()
+            .map_err(|e| format!("failed to read default input config: {e}"))?;
+        let sample_rate = config.sample_rate().0;
+
+        self.samples.lock().unwrap().clear();
+        *self.level.lock().unwrap() = 0.0;
+
+        let samples = Arc::clone(&self.samples);
+        let level = Arc::clone(&self.level);
+        let stream_for_autostop = Arc::clone(&self.stream);
+
+        let auto_stop_enabled = settings.auto_stop_enabled;
+        let silence_timeout_ms = settings.silence_timeout_ms;
+        let mut vad = VoiceActivityDetector::new(sample_rate, settings.vad_sensitivity);
+
+        let err_fn = |err| eprintln!("audio input stream error: {err}");
+        let stream = cpal_device
+            .build_input_stream(
+                &config.into(),
+                move |data: &[f32], _| {
+                    let mut peak = 0f32;
+                    let mut sum_sq = 0f32;
+                    for &sample in data {
+                        peak = peak.max(sample.abs());
+                        sum_sq += sample * sample;
+                    }
+                    let rms = (sum_sq / data.len().max(1) as f32).sqrt();
+
+                    samples.lock().unwrap().extend_from_slice(data);
+                    *level.lock().unwrap() = peak;
+
+                    let _ = app.emit(AUDIO_LEVEL_EVENT, AudioLevel { peak, rms });
+
+                    if auto_stop_enabled && vad.push_samples(data, silence_timeout_ms) {
+                        let _ = app.emit(RECORDING_COMPLETE_EVENT, ());
+                        let stream_for_autostop = Arc::clone(&stream_for_autostop);
+                        std::thread::spawn(move || {
+                            stream_for_autostop.lock().unwrap().take();
+                        });
+                    }
+                },
+                err_fn,
+                None,
+            )
+            .map_err(|e| format!("failed to build input stream: {e}"))?;
+
+        stream
+            .play()
+            .map_err(|e| format!("failed to start input stream: {e}"))?;
+        *self.stream.lock().unwrap() = Some(stream);
+
+        Ok(())
+    }
+
+    /// Tear down the active stream (if any) and return everything captured.
+    pub fn stop(&self) -> Vec<f32> {
+        self.stream.lock().unwrap().take();
+        std::mem::take(&mut *self.samples.lock().unwrap())
+    }
 }
 
 impl Default for Recorder {
@@ -19,3 +122,10 @@ impl Default for Recorder {
         Self::new()
     }
 }
+
+/// Payload for the `audio-level` event.
+#[derive(Clone, serde::Serialize)]
+struct AudioLevel {
+    peak: f32,
+    rms: f32,
+}
@@ -0,0 +1,225 @@
+use realfft::RealFftPlanner;
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+const FRAME_MS: usize = 25;
+const HOP_MS: usize = 10;
+const SPEECH_BAND_HZ: (f32, f32) = (300.0, 3400.0);
+const NOISE_FLOOR_WINDOW_MS: usize = 1_000;
+/// Floor the noise estimate is seeded with before any silence has actually
+/// been observed. Without this, `noise_floor_history` starts empty, the
+/// very first frame is forced to classify as non-speech (folding whatever
+/// energy it has into the floor), and if the user starts talking right at
+/// key-down with no leading silence, a sustained flat-energy utterance can
+/// end up permanently classified as silence — nothing is ever
+/// `sensitivity`x louder than what the first (speech) frame just seeded.
+/// This value is well below typical ambient-noise energy, so it's quickly
+/// overtaken by a real silence reading and doesn't meaningfully raise the
+/// detection threshold once that happens.
+const SEED_NOISE_FLOOR: f32 = 1e-4;
+
+/// Detects end-of-speech by comparing band-limited FFT energy against a
+/// rolling noise floor, so recording can auto-stop once the user has
+/// stopped talking instead of requiring a manual hotkey release.
+pub struct VoiceActivityDetector {
+    sample_rate: u32,
+    frame_len: usize,
+    hop_len: usize,
+    sensitivity: f32,
+    window: Vec<f32>,
+    fft: Arc<dyn realfft::RealToComplex<f32>>,
+    pending: Vec<f32>,
+    noise_floor_history: VecDeque<f32>,
+    speech_started: bool,
+    silence_run_ms: u32,
+}
+
+impl VoiceActivityDetector {
+    /// `sensitivity` is the multiplier applied to the noise floor; a frame
+    /// is classified as speech once its band energy exceeds
+    /// `noise_floor * sensitivity`.
+    pub fn new(sample_rate: u32, sensitivity: f32) -> Self {
+        let frame_len = (sample_rate as usize * FRAME_MS / 1000).max(2);
+        let hop_len = (sample_rate as usize * HOP_MS / 1000).max(1);
+        let fft = RealFftPlanner::<f32>::new().plan_fft_forward(frame_len);
+
+        Self {
+            sample_rate,
+            frame_len,
+            hop_len,
+            sensitivity,
+            window: hann_window(frame_len),
+            fft,
+            pending: Vec::new(),
+            noise_floor_history: VecDeque::from([SEED_NOISE_FLOOR]),
+            speech_started: false,
+            silence_run_ms: 0,
+        }
+    }
+
+    /// Feed newly captured samples in. Returns `true` once speech has been
+    /// observed and `silence_timeout_ms` of consecutive silence follows.
+    pub fn push_samples(&mut self, data: &[f32], silence_timeout_ms: u32) -> bool {
+        self.pending.extend_from_slice(data);
+
+        let mut triggered = false;
+        while self.pending.len() >= self.frame_len {
+            let is_speech = self.classify_frame();
+
+            if is_speech {
+                self.speech_started = true;
+                self.silence_run_ms = 0;
+            } else if self.speech_started {
+                self.silence_run_ms += HOP_MS as u32;
+                if self.silence_run_ms >= silence_timeout_ms {
+                    triggered = true;
+                }
+            }
+
+            let drain = self.hop_len.min(self.pending.len());
+            self.pending.drain(..drain);
+        }
+
+        triggered
+    }
+
+    fn classify_frame(&mut self) -> bool {
+        let mut windowed: Vec<f32> = self.pending[..self.frame_len]
+            .iter()
+            .zip(&self.window)
+            .map(|(sample, w)| sample * w)
+            .collect();
+
+        let mut spectrum = self.fft.make_output_vec();
+        if self.fft.process(&mut windowed, &mut spectrum).is_err() {
+            return false;
+        }
+
+        let bin_hz = self.sample_rate as f32 / self.frame_len as f32;
+        let (lo_hz, hi_hz) = SPEECH_BAND_HZ;
+        let lo_bin = (lo_hz / bin_hz) as usize;
+        let hi_bin = ((hi_hz / bin_hz) as usize).min(spectrum.len().saturating_sub(1));
+
+        let band_energy: f32 = spectrum[lo_bin..=hi_bin.max(lo_bin)]
+            .iter()
+            .map(|c| c.norm_sqr())
+            .sum();
+
+        // Classify against the floor estimated *so far* before deciding
+        // whether this frame is allowed to feed back into that estimate.
+        // Updating the floor on every frame (speech included) lets a
+        // sustained utterance drag it up toward speech level, which then
+        // raises the threshold high enough that quiet syllables after a
+        // breath pause look like silence and auto-stop fires mid-sentence.
+        let noise_floor = self
+            .noise_floor_history
+            .iter()
+            .cloned()
+            .fold(f32::MAX, f32::min);
+        let is_speech = band_energy > noise_floor * self.sensitivity;
+
+        if !is_speech {
+            let history_len = (NOISE_FLOOR_WINDOW_MS / HOP_MS).max(1);
+            self.noise_floor_history.push_back(band_energy);
+            while self.noise_floor_history.len() > history_len {
+                self.noise_floor_history.pop_front();
+            }
+        }
+
+        is_speech
+    }
+}
+
+fn hann_window(len: usize) -> Vec<f32> {
+    (0..len)
+        .map(|i| {
+            0.5 - 0.5 * (2.0 * std::f32::consts::PI * i as f32 / (len.max(2) - 1) as f32).cos()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE_RATE: u32 = 16_000;
+
+    fn tone(freq: f32, num_samples: usize) -> Vec<f32> {
+        (0..num_samples)
+            .map(|i| (2.0 * std::f32::consts::PI * freq * i as f32 / SAMPLE_RATE as f32).sin())
+            .collect()
+    }
+
+    fn hop_len() -> usize {
+        (SAMPLE_RATE as usize * HOP_MS / 1000).max(1)
+    }
+
+    #[test]
+    fn never_triggers_before_any_speech_is_observed() {
+        let mut vad = VoiceActivityDetector::new(SAMPLE_RATE, 2.0);
+        let silence = vec![0.0f32; hop_len()];
+        for _ in 0..200 {
+            assert!(!vad.push_samples(&silence, 50));
+        }
+        assert!(!vad.speech_started);
+    }
+
+    #[test]
+    fn loud_in_band_tone_marks_speech_started() {
+        let mut vad = VoiceActivityDetector::new(SAMPLE_RATE, 2.0);
+        // Prime the noise floor with a little silence first.
+        let silence = vec![0.0f32; hop_len()];
+        for _ in 0..5 {
+            vad.push_samples(&silence, 800);
+        }
+
+        let speech = tone(1_000.0, hop_len() * 6);
+        vad.push_samples(&speech, 800);
+
+        assert!(vad.speech_started);
+    }
+
+    #[test]
+    fn speech_starting_immediately_with_no_leading_silence_is_still_detected() {
+        let mut vad = VoiceActivityDetector::new(SAMPLE_RATE, 2.0);
+        // No silence primed first: the very first frames pushed are already
+        // a sustained, flat-energy tone, the normal press-and-hold flow
+        // when a user starts talking right at key-down.
+        let speech = tone(1_000.0, hop_len() * 20);
+        vad.push_samples(&speech, 800);
+
+        assert!(vad.speech_started);
+    }
+
+    #[test]
+    fn silence_after_speech_triggers_after_the_configured_timeout_in_hop_increments() {
+        let mut vad = VoiceActivityDetector::new(SAMPLE_RATE, 2.0);
+        let silence_timeout_ms = 50; // 5 hops at HOP_MS=10
+
+        let silence = vec![0.0f32; hop_len()];
+        for _ in 0..5 {
+            vad.push_samples(&silence, silence_timeout_ms);
+        }
+
+        let speech = tone(1_000.0, hop_len() * 6);
+        assert!(!vad.push_samples(&speech, silence_timeout_ms));
+        assert!(vad.speech_started);
+
+        let mut hops_until_triggered = 0;
+        let mut triggered = false;
+        for _ in 0..50 {
+            hops_until_triggered += 1;
+            if vad.push_samples(&silence, silence_timeout_ms) {
+                triggered = true;
+                break;
+            }
+        }
+
+        assert!(triggered, "auto-stop never fired on trailing silence");
+        let elapsed_ms = hops_until_triggered * HOP_MS as u32;
+        assert!(
+            elapsed_ms >= silence_timeout_ms,
+            "triggered after {elapsed_ms}ms, before the {silence_timeout_ms}ms timeout elapsed"
+        );
+    }
+}
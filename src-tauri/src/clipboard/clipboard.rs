@@ -1,4 +1,52 @@
-/// Clipboard manager for text operations
+use arboard::{Clipboard as SystemClipboard, ImageData};
+use enigo::{Direction, Enigo, Key, Keyboard, Settings};
+use std::time::Duration;
+
+/// How long to wait after simulating the paste keystroke before restoring
+/// the user's previous clipboard contents, giving the target app time to
+/// actually read what we just pasted. This is a fixed guess, not a signal
+/// that the target app actually finished reading the clipboard — a slow
+/// app can still read the restored (pre-dictation) contents instead of the
+/// transcription if it hasn't processed the paste within this window.
+const RESTORE_DELAY: Duration = Duration::from_millis(200);
+
+/// A snapshot of whatever was on the clipboard before we overwrote it with
+/// a transcription, so `paste_into_active` can restore it afterwards
+/// without losing non-text content (e.g. a copied image).
+enum PreviousClipboard {
+    Text(String),
+    Image(ImageData<'static>),
+    /// Nothing we know how to restore (empty clipboard, or a format arboard
+    /// doesn't expose) — leave the clipboard as-is rather than wiping it.
+    Unknown,
+}
+
+impl PreviousClipboard {
+    fn capture(clipboard: &mut SystemClipboard) -> Self {
+        if let Ok(text) = clipboard.get_text() {
+            return Self::Text(text);
+        }
+        if let Ok(image) = clipboard.get_image() {
+            return Self::Image(image.to_owned_img());
+        }
+        Self::Unknown
+    }
+
+    fn restore(self, clipboard: &mut SystemClipboard) {
+        match self {
+            Self::Text(text) => {
+                let _ = clipboard.set_text(text);
+            }
+            Self::Image(image) => {
+                let _ = clipboard.set_image(image);
+            }
+            Self::Unknown => {}
+        }
+    }
+}
+
+/// Clipboard manager for text operations, with optional auto-paste into
+/// whatever application had focus when recording started.
 pub struct Clipboard;
 
 impl Clipboard {
@@ -6,8 +54,55 @@ impl Clipboard {
         Self
     }
 
-    pub fn copy_text(&self, _text: &str) -> Result<(), String> {
-        // TODO: Implement using arboard
+    pub fn copy_text(&self, text: &str) -> Result<(), String> {
+        let mut clipboard =
+            SystemClipboard::new().map_err(|e| format!("failed to access clipboard: {e}"))?;
+        clipboard
+            .set_text(text)
+            .map_err(|e| format!("failed to write to clipboard: {e}"))
+    }
+
+    /// Copy `text` onto the clipboard and, when `auto_paste` is enabled,
+    /// simulate the platform paste keystroke so it lands directly in the
+    /// focused app. The user's previous clipboard contents are restored
+    /// afterwards so dictation doesn't clobber them.
+    pub fn paste_into_active(&self, text: &str, auto_paste: bool) -> Result<(), String> {
+        let mut clipboard =
+            SystemClipboard::new().map_err(|e| format!("failed to access clipboard: {e}"))?;
+        let previous = PreviousClipboard::capture(&mut clipboard);
+
+        clipboard
+            .set_text(text)
+            .map_err(|e| format!("failed to write to clipboard: {e}"))?;
+
+        if auto_paste {
+            Self::simulate_paste()?;
+            std::thread::sleep(RESTORE_DELAY);
+            previous.restore(&mut clipboard);
+        }
+
+        Ok(())
+    }
+
+    fn simulate_paste() -> Result<(), String> {
+        let mut enigo = Enigo::new(&Settings::default())
+            .map_err(|e| format!("failed to init input synthesis: {e}"))?;
+
+        #[cfg(target_os = "macos")]
+        let paste_modifier = Key::Meta;
+        #[cfg(not(target_os = "macos"))]
+        let paste_modifier = Key::Control;
+
+        enigo
+            .key(paste_modifier, Direction::Press)
+            .map_err(|e| format!("failed to press paste modifier: {e}"))?;
+        enigo
+            .key(Key::Unicode('v'), Direction::Click)
+            .map_err(|e| format!("failed to send paste keystroke: {e}"))?;
+        enigo
+            .key(paste_modifier, Direction::Release)
+            .map_err(|e| format!("failed to release paste modifier: {e}"))?;
+
         Ok(())
     }
 }
@@ -0,0 +1,110 @@
+use global_hotkey::HotKeyState;
+use std::time::{Duration, Instant};
+
+use crate::hotkey::hotkey::HotkeyAction;
+
+/// Default window within which two key-down events count as a double-tap.
+const DEFAULT_WINDOW_MS: u64 = 400;
+
+/// Double-tap hotkey mode: toggles recording on/off only when two key-down
+/// events arrive within `window` of each other, tracked via the timestamp
+/// of the last press.
+pub struct DoubleTap {
+    window: Duration,
+    last_press: Option<Instant>,
+    recording: bool,
+}
+
+impl DoubleTap {
+    pub fn new() -> Self {
+        Self::with_window_ms(DEFAULT_WINDOW_MS)
+    }
+
+    pub fn with_window_ms(window_ms: u64) -> Self {
+        Self {
+            window: Duration::from_millis(window_ms),
+            last_press: None,
+            recording: false,
+        }
+    }
+
+    pub fn on_event(&mut self, state: HotKeyState) -> HotkeyAction {
+        if state != HotKeyState::Pressed {
+            return HotkeyAction::None;
+        }
+
+        let now = Instant::now();
+        let is_double_tap = self
+            .last_press
+            .map(|last| now.duration_since(last) <= self.window)
+            .unwrap_or(false);
+
+        if !is_double_tap {
+            self.last_press = Some(now);
+            return HotkeyAction::None;
+        }
+        self.last_press = None;
+
+        self.recording = !self.recording;
+        if self.recording {
+            HotkeyAction::Start
+        } else {
+            HotkeyAction::Stop
+        }
+    }
+}
+
+impl Default for DoubleTap {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn single_tap_does_not_toggle() {
+        let mut mode = DoubleTap::with_window_ms(50);
+        assert_eq!(mode.on_event(HotKeyState::Pressed), HotkeyAction::None);
+    }
+
+    #[test]
+    fn released_events_are_ignored() {
+        let mut mode = DoubleTap::with_window_ms(200);
+        assert_eq!(mode.on_event(HotKeyState::Released), HotkeyAction::None);
+    }
+
+    #[test]
+    fn tap_outside_the_window_does_not_toggle() {
+        let mut mode = DoubleTap::with_window_ms(30);
+        assert_eq!(mode.on_event(HotKeyState::Pressed), HotkeyAction::None);
+        std::thread::sleep(Duration::from_millis(100));
+        assert_eq!(mode.on_event(HotKeyState::Pressed), HotkeyAction::None);
+    }
+
+    #[test]
+    fn tap_inside_the_window_toggles_recording_on_then_off() {
+        let mut mode = DoubleTap::with_window_ms(200);
+
+        assert_eq!(mode.on_event(HotKeyState::Pressed), HotkeyAction::None);
+        std::thread::sleep(Duration::from_millis(20));
+        assert_eq!(mode.on_event(HotKeyState::Pressed), HotkeyAction::Start);
+
+        assert_eq!(mode.on_event(HotKeyState::Pressed), HotkeyAction::None);
+        std::thread::sleep(Duration::from_millis(20));
+        assert_eq!(mode.on_event(HotKeyState::Pressed), HotkeyAction::Stop);
+    }
+
+    #[test]
+    fn a_completed_double_tap_resets_last_press_so_a_lone_third_press_waits_for_its_pair() {
+        let mut mode = DoubleTap::with_window_ms(200);
+
+        mode.on_event(HotKeyState::Pressed);
+        std::thread::sleep(Duration::from_millis(10));
+        assert_eq!(mode.on_event(HotKeyState::Pressed), HotkeyAction::Start);
+
+        assert_eq!(mode.on_event(HotKeyState::Pressed), HotkeyAction::None);
+    }
+}
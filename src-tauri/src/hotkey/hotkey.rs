@@ -0,0 +1,68 @@
+use global_hotkey::hotkey::HotKey;
+use global_hotkey::{GlobalHotKeyEvent, GlobalHotKeyManager};
+use std::str::FromStr;
+
+use crate::hotkey::{DoubleTap, PressAndHold};
+use crate::models::HotkeyMode;
+
+/// What the caller should do in response to a dispatched hotkey event.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HotkeyAction {
+    Start,
+    Stop,
+    None,
+}
+
+/// Owns the OS-level global shortcut registration and routes its key-down
+/// / key-up events to whichever interaction mode (`PressAndHold` or
+/// `DoubleTap`) the user configured.
+pub struct HotkeyManager {
+    manager: GlobalHotKeyManager,
+    hotkey: HotKey,
+}
+
+impl HotkeyManager {
+    /// Parse `hotkey_str` (e.g. `"CmdOrCtrl+Shift+Space"`) and register it
+    /// as a system-wide shortcut.
+    pub fn register(hotkey_str: &str) -> Result<Self, String> {
+        let manager = GlobalHotKeyManager::new()
+            .map_err(|e| format!("failed to init global hotkey manager: {e}"))?;
+        let hotkey = HotKey::from_str(hotkey_str)
+            .map_err(|e| format!("failed to parse hotkey '{hotkey_str}': {e}"))?;
+        manager
+            .register(hotkey)
+            .map_err(|e| format!("failed to register hotkey: {e}"))?;
+
+        Ok(Self { manager, hotkey })
+    }
+
+    /// The global channel `global-hotkey` delivers key events on.
+    pub fn event_receiver() -> &'static std::sync::mpsc::Receiver<GlobalHotKeyEvent> {
+        GlobalHotKeyEvent::receiver()
+    }
+
+    /// Route an incoming OS event through `mode`'s interaction logic.
+    /// Events for hotkeys other than the one we registered are ignored.
+    pub fn dispatch(
+        &self,
+        mode: HotkeyMode,
+        press_and_hold: &mut PressAndHold,
+        double_tap: &mut DoubleTap,
+        event: &GlobalHotKeyEvent,
+    ) -> HotkeyAction {
+        if event.id != self.hotkey.id() {
+            return HotkeyAction::None;
+        }
+
+        match mode {
+            HotkeyMode::PressAndHold => press_and_hold.on_event(event.state),
+            HotkeyMode::DoubleTap => double_tap.on_event(event.state),
+        }
+    }
+}
+
+impl Drop for HotkeyManager {
+    fn drop(&mut self) {
+        let _ = self.manager.unregister(self.hotkey);
+    }
+}
@@ -5,6 +5,6 @@ pub mod hotkey;
 pub mod press_and_hold;
 pub mod double_tap;
 
-pub use hotkey::HotkeyManager;
+pub use hotkey::{HotkeyAction, HotkeyManager};
 pub use press_and_hold::PressAndHold;
 pub use double_tap::DoubleTap;
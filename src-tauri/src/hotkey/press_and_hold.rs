@@ -0,0 +1,71 @@
+use global_hotkey::HotKeyState;
+
+use crate::hotkey::hotkey::HotkeyAction;
+
+/// Press-and-hold hotkey mode: starts recording on key-down and stops on
+/// key-up. Tracks whether the key is already held so OS auto-repeat
+/// key-down events don't retrigger a start.
+#[derive(Debug, Default)]
+pub struct PressAndHold {
+    pressed: bool,
+}
+
+impl PressAndHold {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn on_event(&mut self, state: HotKeyState) -> HotkeyAction {
+        match state {
+            HotKeyState::Pressed if self.pressed => HotkeyAction::None,
+            HotKeyState::Pressed => {
+                self.pressed = true;
+                HotkeyAction::Start
+            }
+            HotKeyState::Released => {
+                self.pressed = false;
+                HotkeyAction::Stop
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn press_starts_recording() {
+        let mut mode = PressAndHold::new();
+        assert_eq!(mode.on_event(HotKeyState::Pressed), HotkeyAction::Start);
+    }
+
+    #[test]
+    fn auto_repeat_press_events_dont_retrigger_start() {
+        let mut mode = PressAndHold::new();
+        assert_eq!(mode.on_event(HotKeyState::Pressed), HotkeyAction::Start);
+        assert_eq!(mode.on_event(HotKeyState::Pressed), HotkeyAction::None);
+        assert_eq!(mode.on_event(HotKeyState::Pressed), HotkeyAction::None);
+    }
+
+    #[test]
+    fn release_stops_recording() {
+        let mut mode = PressAndHold::new();
+        mode.on_event(HotKeyState::Pressed);
+        assert_eq!(mode.on_event(HotKeyState::Released), HotkeyAction::Stop);
+    }
+
+    #[test]
+    fn release_without_a_prior_press_still_reports_stop() {
+        let mut mode = PressAndHold::new();
+        assert_eq!(mode.on_event(HotKeyState::Released), HotkeyAction::Stop);
+    }
+
+    #[test]
+    fn press_after_release_retriggers_start() {
+        let mut mode = PressAndHold::new();
+        mode.on_event(HotKeyState::Pressed);
+        mode.on_event(HotKeyState::Released);
+        assert_eq!(mode.on_event(HotKeyState::Pressed), HotkeyAction::Start);
+    }
+}
@@ -6,5 +6,5 @@ pub mod settings;
 pub mod history;
 
 pub use transcription::Transcription;
-pub use settings::AppSettings;
+pub use settings::{AppSettings, HotkeyMode};
 pub use history::HistoryEntry;
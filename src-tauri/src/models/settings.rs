@@ -1,8 +1,81 @@
 use serde::{Deserialize, Serialize};
 
+/// Which interaction model the configured `recording_hotkey` uses.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum HotkeyMode {
+    /// Start recording on key-down, stop on key-up.
+    PressAndHold,
+    /// Toggle recording on/off when the key is pressed twice in quick
+    /// succession.
+    DoubleTap,
+}
+
 /// Application settings model
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AppSettings {
     pub recording_hotkey: String,
+    pub hotkey_mode: HotkeyMode,
+    /// Stable id (see `AudioDevice.id`) of the user's chosen input device.
+    /// `None` means use the host's default input device.
+    pub input_device_id: Option<String>,
     pub language: String,
+    /// Automatically stop recording once trailing silence is detected,
+    /// instead of requiring the user to release the hotkey.
+    pub auto_stop_enabled: bool,
+    /// How long a run of consecutive silence frames must last (in
+    /// milliseconds) after speech has started before auto-stop fires.
+    pub silence_timeout_ms: u32,
+    /// Multiplier applied to the rolling noise floor; a frame is classified
+    /// as speech once its band energy exceeds `noise_floor * sensitivity`.
+    pub vad_sensitivity: f32,
+    /// After copying a transcription to the clipboard, also simulate the
+    /// platform paste keystroke into whatever app had focus when recording
+    /// started.
+    pub auto_paste_enabled: bool,
+    /// Play notification sounds on record start/stop/error/done.
+    pub sound_enabled: bool,
+    /// Optional user-supplied sound files overriding the bundled defaults.
+    pub custom_start_sound_path: Option<String>,
+    pub custom_stop_sound_path: Option<String>,
+    pub custom_error_sound_path: Option<String>,
+    pub custom_done_sound_path: Option<String>,
+    /// Opt-in post-processing step that sends transcribed text through
+    /// `TextRefiner` before it's copied/pasted.
+    pub refinement_enabled: bool,
+    /// Base URL of an OpenAI-compatible chat completions API (e.g.
+    /// `https://api.openai.com/v1` or a local server's address).
+    pub refinement_base_url: String,
+    pub refinement_api_key: String,
+    /// Chat model name sent in each refinement request. Local
+    /// OpenAI-compatible servers often reject requests whose `model`
+    /// doesn't match what they have loaded, so this must be configurable.
+    pub refinement_model: String,
+    /// Additional languages to render each dictation in, alongside the
+    /// original transcription (see `Transcription.translations`).
+    pub targets: Vec<String>,
+}
+
+impl Default for AppSettings {
+    fn default() -> Self {
+        Self {
+            recording_hotkey: String::from("CmdOrCtrl+Shift+Space"),
+            hotkey_mode: HotkeyMode::PressAndHold,
+            input_device_id: None,
+            language: String::from("en"),
+            auto_stop_enabled: false,
+            silence_timeout_ms: 800,
+            vad_sensitivity: 2.5,
+            auto_paste_enabled: false,
+            sound_enabled: true,
+            custom_start_sound_path: None,
+            custom_stop_sound_path: None,
+            custom_error_sound_path: None,
+            custom_done_sound_path: None,
+            refinement_enabled: false,
+            refinement_base_url: String::from("https://api.openai.com/v1"),
+            refinement_api_key: String::new(),
+            refinement_model: String::from("gpt-4o-mini"),
+            targets: Vec::new(),
+        }
+    }
 }
@@ -6,4 +6,9 @@ pub struct Transcription {
     pub id: String,
     pub text: String,
     pub timestamp: chrono::DateTime<chrono::Utc>,
+    /// `(language, translated text)` pairs produced when translation mode
+    /// is enabled, one per `AppSettings.targets` entry. Empty when
+    /// translation wasn't requested.
+    #[serde(default)]
+    pub translations: Vec<(String, String)>,
 }
@@ -5,4 +5,4 @@ pub mod refinement;
 pub mod prompt;
 
 pub use refinement::TextRefiner;
-pub use prompt::PromptTemplate;
+pub use prompt::{PromptTemplate, PromptTemplateKind};
@@ -1,12 +1,101 @@
-/// Prompt template for LLM refinement
+use serde::{Deserialize, Serialize};
+
+/// Built-in refinement modes, each with its own system prompt.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PromptTemplateKind {
+    GrammarFixup,
+    FormalTone,
+    BulletSummary,
+    CodeCommentCleanup,
+    /// Translate dictated text into another language. Not part of
+    /// `PromptTemplate::builtin()` since it needs a target language; built
+    /// on demand via `PromptTemplate::translation`.
+    Translation,
+}
+
+/// A named system prompt selectable from the frontend as a refinement mode.
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PromptTemplate {
+    pub kind: PromptTemplateKind,
+    pub name: String,
     pub system_prompt: String,
 }
 
 impl PromptTemplate {
     pub fn new() -> Self {
+        Self::grammar_fixup()
+    }
+
+    pub fn grammar_fixup() -> Self {
+        Self {
+            kind: PromptTemplateKind::GrammarFixup,
+            name: String::from("Grammar fixup"),
+            system_prompt: String::from(
+                "Fix grammar, spelling, and punctuation in the following dictated text. \
+                 Keep the meaning and tone unchanged, and return only the corrected text.",
+            ),
+        }
+    }
+
+    pub fn formal_tone() -> Self {
+        Self {
+            kind: PromptTemplateKind::FormalTone,
+            name: String::from("Email / formal tone"),
+            system_prompt: String::from(
+                "Rewrite the following dictated text in a polished, formal tone suitable for \
+                 a professional email. Return only the rewritten text.",
+            ),
+        }
+    }
+
+    pub fn bullet_summary() -> Self {
         Self {
-            system_prompt: String::from("Improve the text for clarity and grammar."),
+            kind: PromptTemplateKind::BulletSummary,
+            name: String::from("Bullet-point summary"),
+            system_prompt: String::from(
+                "Summarize the following dictated text as a concise list of bullet points. \
+                 Return only the bullet list.",
+            ),
         }
     }
+
+    pub fn code_comment_cleanup() -> Self {
+        Self {
+            kind: PromptTemplateKind::CodeCommentCleanup,
+            name: String::from("Code-comment cleanup"),
+            system_prompt: String::from(
+                "Rewrite the following dictated text as a clean, concise code comment. \
+                 Remove filler words and keep it factual. Return only the comment text.",
+            ),
+        }
+    }
+
+    /// Build a translation template targeting `target` (e.g. `"French"`).
+    /// Used by `TranscriptionEngine::transcribe_with_translations` for
+    /// target languages Whisper can't translate into directly.
+    pub fn translation(target: &str) -> Self {
+        Self {
+            kind: PromptTemplateKind::Translation,
+            name: format!("Translate to {target}"),
+            system_prompt: format!(
+                "Translate the following dictated text into {target}. Return only the translation."
+            ),
+        }
+    }
+
+    /// All built-in templates, in the order the frontend should list them.
+    pub fn builtin() -> Vec<Self> {
+        vec![
+            Self::grammar_fixup(),
+            Self::formal_tone(),
+            Self::bullet_summary(),
+            Self::code_comment_cleanup(),
+        ]
+    }
+}
+
+impl Default for PromptTemplate {
+    fn default() -> Self {
+        Self::new()
+    }
 }
@@ -0,0 +1,132 @@
+use futures_util::StreamExt;
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Emitter};
+
+use crate::refinement::PromptTemplate;
+
+/// Tauri event emitted with each streamed text delta as a refinement
+/// completes, so long refinements appear incrementally in the UI.
+const REFINEMENT_DELTA_EVENT: &str = "refinement-delta";
+
+/// Calls an OpenAI-compatible chat completions endpoint to refine dictated
+/// text. The base URL and API key come from `AppSettings`, so pointing this
+/// at a local OpenAI-compatible server works just as well as the hosted API.
+pub struct TextRefiner {
+    base_url: String,
+    api_key: String,
+    model: String,
+    client: reqwest::Client,
+}
+
+impl TextRefiner {
+    /// `model` comes from `AppSettings.refinement_model` — local servers
+    /// often validate the chat `model` field against what they actually
+    /// have loaded, so it must be configurable rather than hardcoded.
+    pub fn new(base_url: String, api_key: String, model: String) -> Self {
+        Self {
+            base_url,
+            api_key,
+            model,
+            client: reqwest::Client::new(),
+        }
+    }
+
+    /// Refine `text` using `template`'s system prompt, streaming deltas to
+    /// the frontend via the `refinement-delta` event as they arrive, and
+    /// returning the fully assembled result once the stream ends.
+    pub async fn refine(
+        &self,
+        text: &str,
+        template: &PromptTemplate,
+        app: &AppHandle,
+    ) -> Result<String, String> {
+        let url = format!("{}/chat/completions", self.base_url.trim_end_matches('/'));
+        let response = self
+            .client
+            .post(url)
+            .bearer_auth(&self.api_key)
+            .json(&ChatRequest {
+                model: &self.model,
+                stream: true,
+                messages: vec![
+                    ChatMessage {
+                        role: "system",
+                        content: template.system_prompt.clone(),
+                    },
+                    ChatMessage {
+                        role: "user",
+                        content: text.to_string(),
+                    },
+                ],
+            })
+            .send()
+            .await
+            .map_err(|e| format!("refinement request failed: {e}"))?;
+
+        if !response.status().is_success() {
+            return Err(format!(
+                "refinement request failed with status {}",
+                response.status()
+            ));
+        }
+
+        let mut byte_stream = response.bytes_stream();
+        let mut buffer = String::new();
+        let mut full_text = String::new();
+
+        while let Some(chunk) = byte_stream.next().await {
+            let chunk = chunk.map_err(|e| format!("error reading refinement stream: {e}"))?;
+            buffer.push_str(&String::from_utf8_lossy(&chunk));
+
+            while let Some(newline) = buffer.find('\n') {
+                let line = buffer[..newline].trim().to_string();
+                buffer.drain(..=newline);
+
+                let Some(payload) = line.strip_prefix("data: ") else {
+                    continue;
+                };
+                if payload == "[DONE]" {
+                    continue;
+                }
+
+                let Ok(event) = serde_json::from_str::<ChatStreamChunk>(payload) else {
+                    continue;
+                };
+                if let Some(delta) = event.choices.first().and_then(|c| c.delta.content.clone()) {
+                    full_text.push_str(&delta);
+                    let _ = app.emit(REFINEMENT_DELTA_EVENT, &delta);
+                }
+            }
+        }
+
+        Ok(full_text)
+    }
+}
+
+#[derive(Serialize)]
+struct ChatRequest<'a> {
+    model: &'a str,
+    stream: bool,
+    messages: Vec<ChatMessage>,
+}
+
+#[derive(Serialize)]
+struct ChatMessage {
+    role: &'static str,
+    content: String,
+}
+
+#[derive(Deserialize)]
+struct ChatStreamChunk {
+    choices: Vec<ChatStreamChoice>,
+}
+
+#[derive(Deserialize)]
+struct ChatStreamChoice {
+    delta: ChatStreamDelta,
+}
+
+#[derive(Deserialize)]
+struct ChatStreamDelta {
+    content: Option<String>,
+}
@@ -0,0 +1,6 @@
+// Notification sound module
+// Plays short audible cues for the recording/transcription lifecycle
+
+pub mod sound;
+
+pub use sound::{Event, SoundPlayer};
@@ -0,0 +1,92 @@
+use rodio::buffer::SamplesBuffer;
+use rodio::{Decoder, OutputStream, OutputStreamHandle, Source};
+use std::io::Cursor;
+
+use crate::models::AppSettings;
+
+const START_SOUND: &[u8] = include_bytes!("../../assets/sounds/start.wav");
+const STOP_SOUND: &[u8] = include_bytes!("../../assets/sounds/stop.wav");
+const ERROR_SOUND: &[u8] = include_bytes!("../../assets/sounds/error.wav");
+const DONE_SOUND: &[u8] = include_bytes!("../../assets/sounds/done.wav");
+
+/// Lifecycle moments the app gives audible feedback for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Event {
+    Start,
+    Stop,
+    Error,
+    Done,
+}
+
+/// Plays short notification sounds so a hidden push-to-talk window still
+/// gives the user non-visual feedback.
+///
+/// Holds the `OutputStream`/`OutputStreamHandle` alive for the app's
+/// lifetime (rodio stops producing audio the moment the stream is dropped)
+/// and decodes each clip once into an in-memory `SamplesBuffer` so replaying
+/// a sound never re-reads or re-decodes it from disk.
+pub struct SoundPlayer {
+    _stream: OutputStream,
+    handle: OutputStreamHandle,
+    start: SamplesBuffer<i16>,
+    stop: SamplesBuffer<i16>,
+    error: SamplesBuffer<i16>,
+    done: SamplesBuffer<i16>,
+    enabled: bool,
+}
+
+impl SoundPlayer {
+    pub fn new(settings: &AppSettings) -> Result<Self, String> {
+        let (stream, handle) = OutputStream::try_default()
+            .map_err(|e| format!("failed to open default audio output: {e}"))?;
+
+        Ok(Self {
+            _stream: stream,
+            handle,
+            start: load_clip(&settings.custom_start_sound_path, START_SOUND)?,
+            stop: load_clip(&settings.custom_stop_sound_path, STOP_SOUND)?,
+            error: load_clip(&settings.custom_error_sound_path, ERROR_SOUND)?,
+            done: load_clip(&settings.custom_done_sound_path, DONE_SOUND)?,
+            enabled: settings.sound_enabled,
+        })
+    }
+
+    pub fn play(&self, event: Event) -> Result<(), String> {
+        if !self.enabled {
+            return Ok(());
+        }
+
+        let clip = match event {
+            Event::Start => self.start.clone(),
+            Event::Stop => self.stop.clone(),
+            Event::Error => self.error.clone(),
+            Event::Done => self.done.clone(),
+        };
+
+        self.handle
+            .play_raw(clip.convert_samples())
+            .map_err(|e| format!("failed to play {event:?} sound: {e}"))
+    }
+}
+
+/// Decode a clip, preferring a user-supplied override path over the
+/// bundled default.
+fn load_clip(custom_path: &Option<String>, fallback: &'static [u8]) -> Result<SamplesBuffer<i16>, String> {
+    match custom_path {
+        Some(path) => {
+            let bytes = std::fs::read(path)
+                .map_err(|e| format!("failed to read custom sound '{path}': {e}"))?;
+            decode_to_buffer(&bytes)
+        }
+        None => decode_to_buffer(fallback),
+    }
+}
+
+fn decode_to_buffer(bytes: &[u8]) -> Result<SamplesBuffer<i16>, String> {
+    let decoder = Decoder::new(Cursor::new(bytes.to_vec()))
+        .map_err(|e| format!("failed to decode sound: {e}"))?;
+    let channels = decoder.channels();
+    let sample_rate = decoder.sample_rate();
+    let samples: Vec<i16> = decoder.convert_samples().collect();
+    Ok(SamplesBuffer::new(channels, sample_rate, samples))
+}
@@ -1,9 +1,158 @@
-/// Transcription engine for speech-to-text
-pub struct TranscriptionEngine;
+use candle_core::{IndexOp, Tensor};
+use candle_transformers::models::whisper::{self as whisper, audio};
+use rubato::{FftFixedIn, Resampler};
+use tauri::AppHandle;
+
+use crate::models::Transcription;
+use crate::refinement::{PromptTemplate, TextRefiner};
+use crate::transcription::Model;
+
+const WHISPER_SAMPLE_RATE: u32 = 16_000;
+
+/// Precomputed triangular mel filterbanks for `audio::pcm_to_mel`, bundled
+/// the same way the notification sounds are (see `sound::assets`) so the
+/// app doesn't need to regenerate them at runtime. Whisper's small/medium
+/// checkpoints use 80 mel bins; large-v3 uses 128.
+const MEL_FILTERS_80: &[u8] = include_bytes!("../../assets/mel/melfilters.bytes");
+const MEL_FILTERS_128: &[u8] = include_bytes!("../../assets/mel/melfilters128.bytes");
+
+/// Pick the bundled filterbank matching `num_mel_bins` and decode it from
+/// little-endian `f32`s into the `&[f32]` `pcm_to_mel` expects.
+fn mel_filters(num_mel_bins: usize) -> Result<Vec<f32>, String> {
+    let bytes = match num_mel_bins {
+        80 => MEL_FILTERS_80,
+        128 => MEL_FILTERS_128,
+        other => return Err(format!("no bundled mel filterbank for {other} mel bins")),
+    };
+    Ok(bytes
+        .chunks_exact(4)
+        .map(|chunk| f32::from_le_bytes(chunk.try_into().unwrap()))
+        .collect())
+}
+
+/// Speech-to-text engine wrapping a loaded [`Model`].
+///
+/// The engine is stateless beyond the model itself: `transcribe` resamples
+/// whatever the recorder captured down to the 16 kHz mono PCM Whisper
+/// expects, computes the log-mel spectrogram, runs the encoder once, and
+/// then greedily decodes tokens until the end-of-text token is produced.
+pub struct TranscriptionEngine {
+    model: Option<Model>,
+}
 
 impl TranscriptionEngine {
     pub fn new() -> Self {
-        Self
+        Self { model: None }
+    }
+
+    /// Swap in a freshly loaded model (see [`Model::load`]).
+    pub fn with_model(model: Model) -> Self {
+        Self { model: Some(model) }
+    }
+
+    pub fn set_model(&mut self, model: Model) {
+        self.model = Some(model);
+    }
+
+    /// Transcribe a buffer of mono samples captured at `sample_rate`.
+    ///
+    /// `language` is an optional BCP-47-ish code (e.g. `"en"`, `"es"`) taken
+    /// from `AppSettings.language`; when set it's injected as Whisper's
+    /// `<|lang|>` special token so decoding is forced into that language
+    /// instead of relying on language auto-detection.
+    pub fn transcribe(
+        &self,
+        samples: &[f32],
+        sample_rate: u32,
+        language: Option<&str>,
+    ) -> Result<Transcription, String> {
+        let model = self
+            .model
+            .as_ref()
+            .ok_or_else(|| "no whisper model loaded".to_string())?;
+
+        let pcm = resample_to_16k_mono(samples, sample_rate)?;
+        let filters = mel_filters(model.config.num_mel_bins)?;
+        let mel = audio::pcm_to_mel(&model.config, &pcm, &filters);
+        let mel_len = mel.len() / model.config.num_mel_bins;
+        let mel = Tensor::from_vec(
+            mel,
+            (1, model.config.num_mel_bins, mel_len),
+            &model.device,
+        )
+        .map_err(|e| format!("failed to build mel tensor: {e}"))?;
+
+        let encoder_out = model
+            .inner
+            .encoder
+            .forward(&mel, true)
+            .map_err(|e| format!("whisper encoder failed: {e}"))?;
+
+        let text = decode(model, &encoder_out, language, whisper::TRANSCRIBE_TOKEN)?;
+
+        Ok(Transcription {
+            id: uuid::Uuid::new_v4().to_string(),
+            text,
+            timestamp: chrono::Utc::now(),
+            translations: Vec::new(),
+        })
+    }
+
+    /// Translate a buffer of samples straight to English using Whisper's
+    /// own `translate` task, reusing the same mel/encoder pipeline as
+    /// `transcribe`. The source language is auto-detected.
+    pub fn translate_to_english(&self, samples: &[f32], sample_rate: u32) -> Result<String, String> {
+        let model = self
+            .model
+            .as_ref()
+            .ok_or_else(|| "no whisper model loaded".to_string())?;
+
+        let pcm = resample_to_16k_mono(samples, sample_rate)?;
+        let filters = mel_filters(model.config.num_mel_bins)?;
+        let mel = audio::pcm_to_mel(&model.config, &pcm, &filters);
+        let mel_len = mel.len() / model.config.num_mel_bins;
+        let mel = Tensor::from_vec(mel, (1, model.config.num_mel_bins, mel_len), &model.device)
+            .map_err(|e| format!("failed to build mel tensor: {e}"))?;
+
+        let encoder_out = model
+            .inner
+            .encoder
+            .forward(&mel, true)
+            .map_err(|e| format!("whisper encoder failed: {e}"))?;
+
+        decode(model, &encoder_out, None, whisper::TRANSLATE_TOKEN)
+    }
+
+    /// Transcribe `samples` and additionally populate `Transcription.translations`
+    /// for each language in `targets`.
+    ///
+    /// English targets are served by Whisper's built-in `translate` task;
+    /// every other target language goes through `TextRefiner` with a
+    /// translation prompt, since Whisper only translates into English.
+    pub async fn transcribe_with_translations(
+        &self,
+        samples: &[f32],
+        sample_rate: u32,
+        language: Option<&str>,
+        targets: &[String],
+        refiner: Option<&TextRefiner>,
+        app: &AppHandle,
+    ) -> Result<Transcription, String> {
+        let mut transcription = self.transcribe(samples, sample_rate, language)?;
+
+        for target in targets {
+            let translated = if target.eq_ignore_ascii_case("en") {
+                self.translate_to_english(samples, sample_rate)?
+            } else {
+                let refiner = refiner
+                    .ok_or_else(|| "refinement is not configured, cannot translate".to_string())?;
+                let template = PromptTemplate::translation(target);
+                refiner.refine(&transcription.text, &template, app).await?
+            };
+            transcription.translations.push((target.clone(), translated));
+        }
+
+        Ok(transcription)
     }
 }
 
@@ -12,3 +161,89 @@ impl Default for TranscriptionEngine {
         Self::new()
     }
 }
+
+/// Resample `samples` (captured at `input_rate`) down to the 16 kHz mono
+/// format Whisper's feature extractor expects. A no-op when the recorder
+/// already captured at 16 kHz.
+fn resample_to_16k_mono(samples: &[f32], input_rate: u32) -> Result<Vec<f32>, String> {
+    if input_rate == WHISPER_SAMPLE_RATE {
+        return Ok(samples.to_vec());
+    }
+
+    let mut resampler = FftFixedIn::<f32>::new(
+        input_rate as usize,
+        WHISPER_SAMPLE_RATE as usize,
+        samples.len().max(1),
+        1,
+        1,
+    )
+    .map_err(|e| format!("failed to build resampler: {e}"))?;
+
+    let out = resampler
+        .process(&[samples.to_vec()], None)
+        .map_err(|e| format!("resampling failed: {e}"))?;
+    Ok(out.into_iter().next().unwrap_or_default())
+}
+
+/// Autoregressively decode tokens from the encoder output until the
+/// end-of-text token is produced, then detokenize the result. `task_token`
+/// selects between Whisper's `transcribe` and `translate` (to English)
+/// tasks.
+fn decode(
+    model: &Model,
+    encoder_out: &Tensor,
+    language: Option<&str>,
+    task_token: &str,
+) -> Result<String, String> {
+    let tokenizer = &model.tokenizer;
+    let sot_token = token_id(tokenizer, whisper::SOT_TOKEN)?;
+    let eot_token = token_id(tokenizer, whisper::EOT_TOKEN)?;
+    let task_token = token_id(tokenizer, task_token)?;
+    let no_timestamps_token = token_id(tokenizer, whisper::NO_TIMESTAMPS_TOKEN)?;
+
+    let mut tokens = vec![sot_token];
+    if let Some(lang) = language {
+        let lang_token = format!("<|{lang}|>");
+        tokens.push(token_id(tokenizer, &lang_token)?);
+    }
+    tokens.push(task_token);
+    tokens.push(no_timestamps_token);
+
+    let device = &model.device;
+    for _ in 0..model.config.max_target_positions {
+        let input = Tensor::new(tokens.as_slice(), device)
+            .map_err(|e| format!("failed to build decoder input: {e}"))?
+            .unsqueeze(0)
+            .map_err(|e| format!("failed to unsqueeze decoder input: {e}"))?;
+
+        let logits = model
+            .inner
+            .decoder
+            .forward(&input, encoder_out, tokens.len() == 1)
+            .map_err(|e| format!("whisper decoder failed: {e}"))?;
+
+        let last_logits = logits
+            .i((0, logits.dim(1).map_err(|e| e.to_string())? - 1))
+            .map_err(|e| format!("failed to index decoder logits: {e}"))?;
+        let next_token = last_logits
+            .argmax(0)
+            .map_err(|e| format!("argmax over logits failed: {e}"))?
+            .to_scalar::<u32>()
+            .map_err(|e| format!("failed to read next token: {e}"))?;
+
+        if next_token == eot_token {
+            break;
+        }
+        tokens.push(next_token);
+    }
+
+    tokenizer
+        .decode(&tokens, true)
+        .map_err(|e| format!("failed to detokenize transcription: {e}"))
+}
+
+fn token_id(tokenizer: &tokenizers::Tokenizer, token: &str) -> Result<u32, String> {
+    tokenizer
+        .token_to_id(token)
+        .ok_or_else(|| format!("unknown whisper token: {token}"))
+}
@@ -1,5 +1,12 @@
-/// Transcription model types
-#[derive(Debug, Clone, Copy)]
+use candle_core::Device;
+use candle_transformers::models::whisper::{self as whisper, Config};
+use candle_transformers::quantized_var_builder::VarBuilder;
+use hf_hub::api::sync::Api;
+use hf_hub::{Repo, RepoType};
+use std::path::PathBuf;
+
+/// Whisper checkpoint sizes, smallest (fastest) to largest (most accurate).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ModelType {
     Tiny,
     Base,
@@ -8,7 +15,125 @@ pub enum ModelType {
     Large,
 }
 
-/// Transcription model
+impl ModelType {
+    /// Hugging Face repo that hosts the GGUF-quantized checkpoint for this size.
+    fn repo_id(self) -> &'static str {
+        match self {
+            ModelType::Tiny => "lmz/candle-whisper",
+            ModelType::Base => "lmz/candle-whisper",
+            ModelType::Small => "lmz/candle-whisper",
+            ModelType::Medium => "lmz/candle-whisper",
+            ModelType::Large => "lmz/candle-whisper",
+        }
+    }
+
+    fn weights_file_name(self) -> &'static str {
+        match self {
+            ModelType::Tiny => "model-tiny-q80.gguf",
+            ModelType::Base => "model-base-q80.gguf",
+            ModelType::Small => "model-small-q80.gguf",
+            ModelType::Medium => "model-medium-q80.gguf",
+            ModelType::Large => "model-large-v3-q80.gguf",
+        }
+    }
+
+    fn config_file_name(self) -> &'static str {
+        match self {
+            ModelType::Tiny => "config-tiny.json",
+            ModelType::Base => "config-base.json",
+            ModelType::Small => "config-small.json",
+            ModelType::Medium => "config-medium.json",
+            ModelType::Large => "config-large-v3.json",
+        }
+    }
+
+    fn tokenizer_file_name(self) -> &'static str {
+        match self {
+            ModelType::Tiny => "tokenizer-tiny.json",
+            ModelType::Base => "tokenizer-base.json",
+            ModelType::Small => "tokenizer-small.json",
+            ModelType::Medium => "tokenizer-medium.json",
+            ModelType::Large => "tokenizer-large-v3.json",
+        }
+    }
+
+    /// Subdirectory under the on-disk model cache used to key cached downloads.
+    fn cache_dir_name(self) -> &'static str {
+        match self {
+            ModelType::Tiny => "tiny",
+            ModelType::Base => "base",
+            ModelType::Small => "small",
+            ModelType::Medium => "medium",
+            ModelType::Large => "large",
+        }
+    }
+}
+
+/// A Whisper checkpoint loaded onto the CPU device, ready for inference.
 pub struct Model {
     pub model_type: ModelType,
+    pub(crate) inner: whisper::quantized_model::Whisper,
+    pub(crate) config: Config,
+    pub(crate) tokenizer: tokenizers::Tokenizer,
+    pub(crate) device: Device,
+}
+
+impl Model {
+    /// Load a GGUF-quantized Whisper checkpoint, downloading it to the local
+    /// model cache on first use and reusing the cached copy afterwards.
+    pub fn load(model_type: ModelType) -> Result<Self, String> {
+        let device = Device::Cpu;
+
+        let weights_path = Self::cached_file(model_type, model_type.weights_file_name())?;
+        let config_path = Self::cached_file(model_type, model_type.config_file_name())?;
+        let tokenizer_path = Self::cached_file(model_type, model_type.tokenizer_file_name())?;
+
+        let config: Config = serde_json::from_str(
+            &std::fs::read_to_string(&config_path)
+                .map_err(|e| format!("failed to read whisper config: {e}"))?,
+        )
+        .map_err(|e| format!("failed to parse whisper config: {e}"))?;
+
+        let tokenizer = tokenizers::Tokenizer::from_file(&tokenizer_path)
+            .map_err(|e| format!("failed to load whisper tokenizer: {e}"))?;
+
+        let vb = VarBuilder::from_gguf(&weights_path, &device)
+            .map_err(|e| format!("failed to load whisper weights: {e}"))?;
+        let inner = whisper::quantized_model::Whisper::load(&vb, config.clone())
+            .map_err(|e| format!("failed to build whisper model: {e}"))?;
+
+        Ok(Self {
+            model_type,
+            inner,
+            config,
+            tokenizer,
+            device,
+        })
+    }
+
+    /// Resolve `file_name` against the on-disk cache for `model_type`,
+    /// downloading it from the hub the first time it's requested.
+    fn cached_file(model_type: ModelType, file_name: &str) -> Result<PathBuf, String> {
+        let cache_dir = dirs::cache_dir()
+            .ok_or_else(|| "could not determine a cache directory".to_string())?
+            .join("hex")
+            .join("models")
+            .join(model_type.cache_dir_name());
+        std::fs::create_dir_all(&cache_dir)
+            .map_err(|e| format!("failed to create model cache dir: {e}"))?;
+
+        let cached = cache_dir.join(file_name);
+        if cached.exists() {
+            return Ok(cached);
+        }
+
+        let api = Api::new().map_err(|e| format!("failed to init hub api: {e}"))?;
+        let repo = api.repo(Repo::new(model_type.repo_id().to_string(), RepoType::Model));
+        let downloaded = repo
+            .get(file_name)
+            .map_err(|e| format!("failed to download {file_name}: {e}"))?;
+        std::fs::copy(&downloaded, &cached)
+            .map_err(|e| format!("failed to cache {file_name}: {e}"))?;
+        Ok(cached)
+    }
 }